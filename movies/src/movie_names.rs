@@ -1,5 +1,6 @@
 // Summary: Provides a movie database by loading movie titles and IDs from a CSV file.
-// It allows looking up a movie’s title by its numeric ID.
+// It allows looking up a movie’s title by its numeric ID, or fuzzily by title text
+// when the caller knows a film's name (or a rough typo of it) but not its ID.
 // Uses a HashMap internally for fast lookups.
 use std::collections::HashMap;
 use std::error::Error;
@@ -11,37 +12,95 @@ use serde::Deserialize;
 // Fields:
 // - movie_id: unique numeric ID of the movie (from the CSV column "movieId")
 // - title: the title of the movie (e.g., "Real Genius (1985)")
+// - genres: pipe-delimited genre list (e.g., "Action|Adventure|Sci-Fi"), "(no genres
+//   listed)" for movies MovieLens couldn't tag
 #[derive(Debug, Deserialize)]
 struct Movie {
     #[serde(rename = "movieId")]
     movie_id: u32,
     title: String,
+    genres: String,
 }
 
 // Struct MovieDb
 // Represents a movie database
-// Internally stores a HashMap<u32, String> that maps movie IDs to their titles
+// Internally stores a HashMap<u32, String> that maps movie IDs to their titles, a
+// reverse index from normalized title token to movie IDs for fast fuzzy lookup, and
+// each movie's multi-hot genre vector (aligned to `genre_names`) for content-based
+// similarity.
 pub struct MovieDb {
     movies: HashMap<u32, String>,
+    token_index: HashMap<String, Vec<u32>>,
+    genre_names: Vec<String>,
+    genre_vectors: HashMap<u32, Vec<f32>>,
 }
 
 impl MovieDb {
     // Load the movie database from a CSV file
     // Input:
-    // - path: &str --> path to the CSV file containing movieId and title columns
+    // - path: &str --> path to the CSV file containing movieId, title, and genres columns
     // Output:
     // - Result<MovieDb, Error> --> MovieDb instance on success
     // Logic:
     // - Open and parse the CSV file
-    // - For each row, insert (movie_id, title) into the HashMap
+    // - For each row, insert (movie_id, title) into the HashMap, index its title tokens,
+    //   and record its pipe-delimited genres
+    // - Once every genre has been seen, build one multi-hot vector per movie
     pub fn from_path(path: &str) -> Result<Self, Box<dyn Error>> {
         let mut rdr = Reader::from_path(path)?;
         let mut movies = HashMap::new();
+        let mut token_index: HashMap<String, Vec<u32>> = HashMap::new();
+        let mut raw_genres: HashMap<u32, Vec<String>> = HashMap::new();
+        let mut genre_names: Vec<String> = Vec::new();
         for result in rdr.deserialize() {
             let rec: Movie = result?; // Deserialize each CSV row into a Movie struct
+            for token in tokenize(&normalize_title(&rec.title)) {
+                token_index.entry(token).or_default().push(rec.movie_id);
+            }
+
+            let genres: Vec<String> = rec
+                .genres
+                .split('|')
+                .map(str::to_string)
+                .filter(|g| g != "(no genres listed)")
+                .collect();
+            for genre in &genres {
+                if !genre_names.contains(genre) {
+                    genre_names.push(genre.clone());
+                }
+            }
+            raw_genres.insert(rec.movie_id, genres);
+
             movies.insert(rec.movie_id, rec.title); // Add to the map
         }
-        Ok(MovieDb { movies })
+
+        let genre_pos: HashMap<&str, usize> =
+            genre_names.iter().enumerate().map(|(i, g)| (g.as_str(), i)).collect();
+        let genre_vectors = raw_genres
+            .into_iter()
+            .map(|(mid, genres)| {
+                let mut vec = vec![0.0; genre_names.len()];
+                for genre in &genres {
+                    vec[genre_pos[genre.as_str()]] = 1.0;
+                }
+                (mid, vec)
+            })
+            .collect();
+
+        Ok(MovieDb { movies, token_index, genre_names, genre_vectors })
+    }
+
+    // Get a movie's multi-hot genre vector, aligned to the same genre ordering for
+    // every movie in the database.
+    // Output: Option<&[f32]> --> Some(vector) if the movie is known, else None
+    pub fn genre_vector(&self, movie_id: u32) -> Option<&[f32]> {
+        self.genre_vectors.get(&movie_id).map(|v| v.as_slice())
+    }
+
+    // Number of distinct genres seen across the database, i.e. the length of every
+    // genre_vector().
+    pub fn genre_count(&self) -> usize {
+        self.genre_names.len()
     }
 
    // Get the title of a movie by its ID
@@ -52,6 +111,121 @@ impl MovieDb {
     pub fn get_title(&self, movie_id: u32) -> Option<&str> {
         self.movies.get(&movie_id).map(|s| s.as_str())
     }
+
+    // Fuzzily search for movies by title text, so a caller who knows a film's name
+    // (maybe with a typo) but not its numeric ID can still look it up.
+    // Input:
+    // - query: &str --> free-text title, e.g. "star wars" or a typo like "stpr wars"
+    // - limit: usize --> maximum number of matches to return
+    // Output:
+    // - Vec<(u32, &str, f32)> --> (movie_id, title, score) triples, best match first
+    // Logic:
+    // 1. Normalize the query (lowercase, strip trailing "(year)") and tokenize it
+    // 2. Use the token index to gather candidates that share at least one token
+    // 3. If no candidates share a token (e.g. every query token is a typo), fall back
+    //    to scanning every movie so a Levenshtein-based match can still find it
+    // 4. Score each candidate with token_set_ratio, sort descending, take the top-`limit`
+    pub fn search_title(&self, query: &str, limit: usize) -> Vec<(u32, &str, f32)> {
+        let normalized_query = normalize_title(query);
+        let query_tokens = tokenize(&normalized_query);
+
+        let mut candidates: Vec<u32> = query_tokens
+            .iter()
+            .filter_map(|t| self.token_index.get(t))
+            .flatten()
+            .copied()
+            .collect();
+        candidates.sort_unstable();
+        candidates.dedup();
+        if candidates.is_empty() {
+            candidates = self.movies.keys().copied().collect();
+        }
+
+        let mut scored: Vec<(u32, &str, f32)> = candidates
+            .into_iter()
+            .map(|mid| {
+                let title = self.movies[&mid].as_str();
+                let score = token_set_ratio(&normalized_query, &query_tokens, &normalize_title(title));
+                (mid, title, score)
+            })
+            .collect();
+        scored.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap().then(a.0.cmp(&b.0)));
+        scored.truncate(limit);
+        scored
+    }
+}
+
+// Lowercase a title and strip a trailing "(YYYY)" year suffix, since that suffix is
+// MovieLens metadata rather than part of the title a user would actually type.
+fn normalize_title(title: &str) -> String {
+    let lower = title.to_lowercase();
+    if let Some(open) = lower.rfind('(') {
+        if lower.ends_with(')') && lower.len() > open + 1 {
+            let inner = &lower[open + 1..lower.len() - 1];
+            if inner.len() == 4 && inner.chars().all(|c| c.is_ascii_digit()) {
+                return lower[..open].trim_end().to_string();
+            }
+        }
+    }
+    lower
+}
+
+// Split a normalized title into whitespace-delimited tokens.
+fn tokenize(normalized: &str) -> Vec<String> {
+    normalized.split_whitespace().map(str::to_string).collect()
+}
+
+// Levenshtein edit distance between two strings (insert/delete/substitute, cost 1).
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        let mut prev_diag = row[0];
+        row[0] = i;
+        for j in 1..=b.len() {
+            let temp = row[j];
+            row[j] = if a[i - 1] == b[j - 1] {
+                prev_diag
+            } else {
+                1 + prev_diag.min(row[j]).min(row[j - 1])
+            };
+            prev_diag = temp;
+        }
+    }
+    row[b.len()]
+}
+
+// Normalized edit-distance similarity in [0, 1]: 1.0 for identical strings, 0.0 when
+// the edit distance is at least as large as the longer string.
+fn edit_similarity(a: &str, b: &str) -> f32 {
+    let max_len = a.chars().count().max(b.chars().count()).max(1);
+    1.0 - (levenshtein(a, b) as f32 / max_len as f32)
+}
+
+// Score a query against a candidate title: mostly a token-set ratio (how many query
+// tokens have a close match among the title's tokens, tolerant of typos within a
+// token), blended with a whole-string edit-distance similarity as a tie-breaker and
+// fallback for queries whose tokens don't line up with the title's word boundaries.
+fn token_set_ratio(normalized_query: &str, query_tokens: &[String], normalized_title: &str) -> f32 {
+    let title_tokens = tokenize(normalized_title);
+    let token_score = if query_tokens.is_empty() || title_tokens.is_empty() {
+        0.0
+    } else {
+        let total: f32 = query_tokens
+            .iter()
+            .map(|qt| {
+                title_tokens
+                    .iter()
+                    .map(|tt| edit_similarity(qt, tt))
+                    .fold(0.0_f32, f32::max)
+            })
+            .sum();
+        total / query_tokens.len() as f32
+    };
+
+    let whole_string_score = edit_similarity(normalized_query, normalized_title);
+    0.7 * token_score + 0.3 * whole_string_score
 }
 
 #[cfg(test)]
@@ -71,4 +245,31 @@ mod tests {
         assert_eq!(db.get_title(88744), Some("Rise of the Planet of the Apes (2011)"));
         Ok(())
     }
+
+    // Test: an exact (lowercased) title query should return that movie first.
+    #[test]
+    fn test_search_title_exact_match() -> Result<(), Box<dyn Error>> {
+        let db = MovieDb::from_path("movies.csv")?;
+        let hits = db.search_title("ghost dad", 3);
+        assert_eq!(hits[0].0, 26686);
+        Ok(())
+    }
+
+    // Test: a typo'd query with no exact token overlap should still resolve to the
+    // intended movie via the Levenshtein fallback over the full candidate set.
+    #[test]
+    fn test_search_title_typo_fallback() -> Result<(), Box<dyn Error>> {
+        let db = MovieDb::from_path("movies.csv")?;
+        let hits = db.search_title("jeffery", 3);
+        assert_eq!(hits[0].0, 171);
+        Ok(())
+    }
+
+    // Test: a query ending in a stray, unclosed "(" must not panic when normalizing
+    // (the trailing-"(" slice bound was previously computed before checking that the
+    // string actually ends with ")").
+    #[test]
+    fn test_normalize_title_trailing_unclosed_paren_does_not_panic() {
+        assert_eq!(normalize_title("Untitled ("), "untitled (");
+    }
 }
\ No newline at end of file
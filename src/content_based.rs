@@ -0,0 +1,160 @@
+// Summary: Provides a content-based recommender over movie genres, so cold-start
+// users (or anyone we have no neighbors for) can still get recommendations, and so
+// a recommendation can be explained by content ("more Sci-Fi like X") rather than
+// only by "users like you" structure. Also exposes a hybrid blend with a
+// collaborative predicted score, since content and collaborative signals catch
+// different things.
+
+use std::collections::HashSet;
+
+use crate::item_cf::ItemCfModel;
+use crate::movie_names::MovieDb;
+use crate::Rating;
+
+// Cosine similarity between two genre vectors (reuses the same formula as the
+// user-vector cosine similarity in main.rs, just over a multi-hot genre space).
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let na: f32 = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let nb: f32 = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if na == 0.0 || nb == 0.0 { 0.0 } else { dot / (na * nb) }
+}
+
+// Build a user's taste profile as the rating-weighted average of the genre vectors
+// of every movie they rated.
+// Output: Option<Vec<f32>> --> None if the user has no known rated movies
+fn build_user_profile(user_id: u32, ratings: &[Rating], movie_db: &MovieDb) -> Option<Vec<f32>> {
+    let n = movie_db.genre_count();
+    let mut profile = vec![0.0f32; n];
+    let mut weight_total = 0.0f32;
+
+    for r in ratings.iter().filter(|r| r.user_id == user_id) {
+        if let Some(genres) = movie_db.genre_vector(r.movie_id) {
+            for (i, &g) in genres.iter().enumerate() {
+                profile[i] += g * r.rating;
+            }
+            weight_total += r.rating;
+        }
+    }
+
+    if weight_total == 0.0 {
+        return None;
+    }
+    for v in &mut profile {
+        *v /= weight_total;
+    }
+    Some(profile)
+}
+
+// Recommend unrated movies for a user by cosine similarity between their genre
+// taste profile and each candidate movie's genre vector.
+// Inputs:
+// - user_id: target user
+// - ratings: &[Rating] --> all ratings
+// - movie_db: &MovieDb --> provides genre vectors
+// - top_n: usize --> number of recommendations
+// Output: Vec<(u32, f32)> --> (movie_id, content score) pairs, highest first
+pub fn recommend_content_based(
+    user_id: u32,
+    ratings: &[Rating],
+    movie_db: &MovieDb,
+    top_n: usize,
+) -> Vec<(u32, f32)> {
+    let Some(profile) = build_user_profile(user_id, ratings, movie_db) else { return Vec::new(); };
+
+    let rated: HashSet<u32> = ratings
+        .iter()
+        .filter(|r| r.user_id == user_id)
+        .map(|r| r.movie_id)
+        .collect();
+
+    let mut mids: Vec<u32> = ratings.iter().map(|r| r.movie_id).collect();
+    mids.sort_unstable();
+    mids.dedup();
+
+    let mut preds: Vec<(u32, f32)> = mids
+        .into_iter()
+        .filter(|mid| !rated.contains(mid))
+        .filter_map(|mid| movie_db.genre_vector(mid).map(|g| (mid, cosine_similarity(&profile, g))))
+        .collect();
+    preds.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap().then(a.0.cmp(&b.0)));
+    preds.truncate(top_n);
+    preds
+}
+
+// Blend a content-based score with a collaborative predicted score for the same
+// movie. `weight` is the fraction of the final score coming from content (0.0 is
+// pure collaborative, 1.0 is pure content), so callers can tune the mix.
+pub fn hybrid_score(content_score: f32, collaborative_score: f32, weight: f32) -> f32 {
+    weight * content_score + (1.0 - weight) * collaborative_score
+}
+
+// Recommend unrated movies for a user by blending their genre-based content score
+// with an item-based collaborative filtering prediction for the same movie.
+// Inputs:
+// - user_id: target user
+// - ratings: &[Rating] --> all ratings
+// - movie_db: &MovieDb --> provides genre vectors
+// - top_n: usize --> number of recommendations
+// - weight: f32 --> fraction of the blend coming from content (see hybrid_score)
+// Output: Vec<(u32, f32)> --> (movie_id, blended score) pairs, highest first
+pub fn recommend_hybrid(
+    user_id: u32,
+    ratings: &[Rating],
+    movie_db: &MovieDb,
+    top_n: usize,
+    weight: f32,
+) -> Vec<(u32, f32)> {
+    let Some(profile) = build_user_profile(user_id, ratings, movie_db) else { return Vec::new(); };
+    let item_cf = ItemCfModel::train(ratings);
+
+    let rated: HashSet<u32> = ratings
+        .iter()
+        .filter(|r| r.user_id == user_id)
+        .map(|r| r.movie_id)
+        .collect();
+
+    let mut mids: Vec<u32> = ratings.iter().map(|r| r.movie_id).collect();
+    mids.sort_unstable();
+    mids.dedup();
+
+    let mut preds: Vec<(u32, f32)> = mids
+        .into_iter()
+        .filter(|mid| !rated.contains(mid))
+        .filter_map(|mid| {
+            movie_db.genre_vector(mid).map(|g| {
+                let content_score = cosine_similarity(&profile, g);
+                let collaborative_score = item_cf.predict(user_id, mid, ratings);
+                (mid, hybrid_score(content_score, collaborative_score, weight))
+            })
+        })
+        .collect();
+    preds.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap().then(a.0.cmp(&b.0)));
+    preds.truncate(top_n);
+    preds
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cosine(a: &[f32], b: &[f32]) -> f32 {
+        cosine_similarity(a, b)
+    }
+
+    // Test: identical genre vectors are perfectly similar, orthogonal ones are not.
+    #[test]
+    fn test_cosine_similarity_genre_vectors() {
+        assert_eq!(cosine(&[1.0, 0.0], &[1.0, 0.0]), 1.0);
+        assert_eq!(cosine(&[1.0, 0.0], &[0.0, 1.0]), 0.0);
+    }
+
+    // Test: blending with weight=1.0 returns pure content, weight=0.0 returns pure
+    // collaborative, and 0.5 averages the two.
+    #[test]
+    fn test_hybrid_score_blends_by_weight() {
+        assert_eq!(hybrid_score(1.0, 0.0, 1.0), 1.0);
+        assert_eq!(hybrid_score(1.0, 0.0, 0.0), 0.0);
+        assert_eq!(hybrid_score(1.0, 0.0, 0.5), 0.5);
+    }
+}
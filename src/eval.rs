@@ -0,0 +1,234 @@
+// Summary: Provides a held-out evaluation harness for measuring recommendation
+// quality. Splits ratings into train/test, fits a predictor on the train set, and
+// reports RMSE and MAE over the test set so different recommenders (and their
+// hyperparameters, e.g. SVD's k or the neighborhood size) can be compared on equal
+// footing against a simple baseline instead of judged only by eyeballing movie IDs.
+
+use std::collections::HashMap;
+
+use crate::item_cf::ItemCfModel;
+use crate::svd_reco::SvdModel;
+use crate::Rating;
+
+// Trait Predictor: anything that can score a single (user, movie) pair after being
+// trained on a train split. Implemented by the baseline, bias, SVD, and item-based
+// models so `evaluate` can score them all the same way.
+pub trait Predictor {
+    fn predict(&self, user_id: u32, movie_id: u32) -> f32;
+}
+
+// Struct GlobalAverageModel: the trivial baseline, r̂ = μ for every (user, movie).
+pub struct GlobalAverageModel {
+    mu: f32,
+}
+
+impl GlobalAverageModel {
+    pub fn train(ratings: &[Rating]) -> Self {
+        let mu = ratings.iter().map(|r| r.rating).sum::<f32>() / ratings.len() as f32;
+        GlobalAverageModel { mu }
+    }
+}
+
+impl Predictor for GlobalAverageModel {
+    fn predict(&self, _user_id: u32, _movie_id: u32) -> f32 {
+        self.mu
+    }
+}
+
+// Struct BiasModel: r̂ = μ + b_u + b_i, where b_u and b_i are each user's and movie's
+// mean deviation from μ. A step up from GlobalAverageModel that still ignores
+// cross-user structure, so it's the natural bar neighborhood/SVD methods must clear.
+pub struct BiasModel {
+    mu: f32,
+    user_bias: HashMap<u32, f32>,
+    item_bias: HashMap<u32, f32>,
+}
+
+impl BiasModel {
+    pub fn train(ratings: &[Rating]) -> Self {
+        let mu = ratings.iter().map(|r| r.rating).sum::<f32>() / ratings.len() as f32;
+
+        let mut user_sum: HashMap<u32, (f32, u32)> = HashMap::new();
+        let mut item_sum: HashMap<u32, (f32, u32)> = HashMap::new();
+        for r in ratings {
+            let u = user_sum.entry(r.user_id).or_insert((0.0, 0));
+            u.0 += r.rating - mu;
+            u.1 += 1;
+            let i = item_sum.entry(r.movie_id).or_insert((0.0, 0));
+            i.0 += r.rating - mu;
+            i.1 += 1;
+        }
+
+        let user_bias = user_sum.into_iter().map(|(uid, (sum, n))| (uid, sum / n as f32)).collect();
+        let item_bias = item_sum.into_iter().map(|(mid, (sum, n))| (mid, sum / n as f32)).collect();
+
+        BiasModel { mu, user_bias, item_bias }
+    }
+}
+
+impl Predictor for BiasModel {
+    fn predict(&self, user_id: u32, movie_id: u32) -> f32 {
+        let b_u = self.user_bias.get(&user_id).copied().unwrap_or(0.0);
+        let b_i = self.item_bias.get(&movie_id).copied().unwrap_or(0.0);
+        self.mu + b_u + b_i
+    }
+}
+
+impl Predictor for SvdModel {
+    fn predict(&self, user_id: u32, movie_id: u32) -> f32 {
+        SvdModel::predict(self, user_id, movie_id)
+    }
+}
+
+// ItemCfModel needs the train-split ratings at prediction time (to look up the
+// target user's rated movies), so it's wrapped alongside them rather than
+// implementing Predictor directly on ItemCfModel itself.
+pub struct ItemCfPredictor<'a> {
+    pub model: ItemCfModel,
+    pub train: &'a [Rating],
+}
+
+impl Predictor for ItemCfPredictor<'_> {
+    fn predict(&self, user_id: u32, movie_id: u32) -> f32 {
+        self.model.predict(user_id, movie_id, self.train)
+    }
+}
+
+// Split ratings into a train/test pair via leave-one-out per user: each user's
+// single most recent rating (by timestamp, same ordering `top_movies` sorts on)
+// becomes a test case, and everything else is training data.
+// Input: &[Rating] --> all ratings
+// Output: (Vec<Rating>, Vec<Rating>) --> (train, test)
+pub fn leave_one_out_split(ratings: &[Rating]) -> (Vec<Rating>, Vec<Rating>) {
+    let mut by_user: HashMap<u32, Vec<&Rating>> = HashMap::new();
+    for r in ratings {
+        by_user.entry(r.user_id).or_default().push(r);
+    }
+
+    let mut train = Vec::new();
+    let mut test = Vec::new();
+    for (_, mut user_ratings) in by_user {
+        user_ratings.sort_by_key(|r| r.timestamp);
+        let (held_out, rest) = user_ratings.split_last().expect("or_default never inserts empty vecs");
+        test.push(**held_out);
+        train.extend(rest.iter().map(|r| **r));
+    }
+    (train, test)
+}
+
+// Split ratings into a train/test pair by a simple chronological ratio: ratings are
+// ordered by timestamp (ties keep their input order) and the most recent
+// test_ratio of them become the test set.
+// Input: ratings, test_ratio (e.g. 0.2 for a 20% held-out test set)
+// Output: (Vec<Rating>, Vec<Rating>) --> (train, test)
+pub fn ratio_split(ratings: &[Rating], test_ratio: f32) -> (Vec<Rating>, Vec<Rating>) {
+    let mut sorted = ratings.to_vec();
+    sorted.sort_by_key(|r| r.timestamp);
+    let n_test = ((sorted.len() as f32) * test_ratio).round() as usize;
+    let split_at = sorted.len().saturating_sub(n_test);
+    let test = sorted.split_off(split_at);
+    (sorted, test)
+}
+
+// Struct EvalResult: the two headline error metrics for a predictor over a test set.
+#[derive(Debug, Clone, Copy)]
+pub struct EvalResult {
+    pub rmse: f32,
+    pub mae: f32,
+}
+
+// Score a trained predictor against held-out ratings.
+// Output: EvalResult --> RMSE = sqrt(mean((r − r̂)²)) and MAE = mean(|r − r̂|)
+pub fn evaluate(predictor: &dyn Predictor, test: &[Rating]) -> EvalResult {
+    let mut sq_err_sum = 0.0;
+    let mut abs_err_sum = 0.0;
+    for r in test {
+        let pred = predictor.predict(r.user_id, r.movie_id);
+        let err = r.rating - pred;
+        sq_err_sum += err * err;
+        abs_err_sum += err.abs();
+    }
+    let n = test.len() as f32;
+    EvalResult { rmse: (sq_err_sum / n).sqrt(), mae: abs_err_sum / n }
+}
+
+// Train the global-average baseline, the bias model, an SVD model (k=20), and the
+// item-based CF model on a given train split and print an RMSE/MAE table, so k and
+// neighborhood-size tuning can be judged against a common baseline.
+fn report_split(label: &str, train: &[Rating], test: &[Rating]) {
+    let baseline = GlobalAverageModel::train(train);
+    let bias = BiasModel::train(train);
+    let svd = SvdModel::train(train, 20);
+    let item_cf = ItemCfPredictor { model: ItemCfModel::train(train), train };
+
+    println!("\n{} ({} train / {} test):", label, train.len(), test.len());
+    println!("{:<20} {:>8} {:>8}", "model", "rmse", "mae");
+    for (name, predictor) in [
+        ("global average", &baseline as &dyn Predictor),
+        ("bias (mu+bu+bi)", &bias as &dyn Predictor),
+        ("svd (k=20)", &svd as &dyn Predictor),
+        ("item-based cf", &item_cf as &dyn Predictor),
+    ] {
+        let result = evaluate(predictor, test);
+        println!("{:<20} {:>8.4} {:>8.4}", name, result.rmse, result.mae);
+    }
+}
+
+// Report RMSE/MAE under both splitting strategies: leave-one-out per user (each
+// user's most recent rating held out) and a simple chronological ratio split.
+pub fn report(ratings: &[Rating]) {
+    let (loo_train, loo_test) = leave_one_out_split(ratings);
+    report_split("leave-one-out split", &loo_train, &loo_test);
+
+    let (ratio_train, ratio_test) = ratio_split(ratings, 0.2);
+    report_split("ratio split (20% test)", &ratio_train, &ratio_test);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rating(user_id: u32, movie_id: u32, rating: f32, timestamp: u64) -> Rating {
+        Rating { user_id, movie_id, rating, timestamp }
+    }
+
+    // Test: the global-average baseline predicts μ for everyone, so its RMSE/MAE on a
+    // test set should match the plain deviation from that mean.
+    #[test]
+    fn test_global_average_matches_manual_mean() {
+        let train = vec![rating(1, 1, 2.0, 0), rating(1, 2, 4.0, 1), rating(2, 1, 3.0, 2)];
+        let test = vec![rating(3, 5, 5.0, 3)];
+        let model = GlobalAverageModel::train(&train);
+        let result = evaluate(&model, &test);
+        // mu = 3.0, so error on the single test point is |5.0 - 3.0| = 2.0
+        assert!((result.mae - 2.0).abs() < 1e-5);
+        assert!((result.rmse - 2.0).abs() < 1e-5);
+    }
+
+    // Test: leave_one_out_split puts exactly one rating per user into the test set,
+    // and it's always that user's most recent one.
+    #[test]
+    fn test_leave_one_out_split_holds_out_most_recent() {
+        let ratings = vec![
+            rating(1, 10, 3.0, 100),
+            rating(1, 20, 4.0, 200),
+            rating(2, 30, 5.0, 50),
+        ];
+        let (train, test) = leave_one_out_split(&ratings);
+        assert_eq!(train.len(), 1);
+        assert_eq!(test.len(), 2);
+        let user1_test = test.iter().find(|r| r.user_id == 1).unwrap();
+        assert_eq!(user1_test.movie_id, 20);
+    }
+
+    // Test: ratio_split puts roughly test_ratio of the ratings into the test set,
+    // preserving chronological order between the two halves.
+    #[test]
+    fn test_ratio_split_sizes_by_ratio() {
+        let ratings: Vec<Rating> = (0..10).map(|i| rating(1, i, 3.0, i as u64)).collect();
+        let (train, test) = ratio_split(&ratings, 0.2);
+        assert_eq!(train.len(), 8);
+        assert_eq!(test.len(), 2);
+        assert_eq!(test[0].movie_id, 8);
+    }
+}
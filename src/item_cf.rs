@@ -0,0 +1,203 @@
+// Summary: Provides an item-based collaborative filtering recommender using
+// mean-centered (adjusted) cosine similarity between movies. Item-item similarity
+// is more stable than user-user similarity for a fixed catalog, and centering each
+// item's ratings around its own mean corrects for users rating on different scales.
+
+use std::collections::HashMap;
+
+use crate::Rating;
+
+// Compute each movie's mean rating over the users who rated it.
+// Input: &[Rating] --> all ratings
+// Output: HashMap<u32, f32> --> movie ID --> mean rating
+fn item_means(ratings: &[Rating]) -> HashMap<u32, f32> {
+    let mut sums: HashMap<u32, (f32, u32)> = HashMap::new();
+    for r in ratings {
+        let entry = sums.entry(r.movie_id).or_insert((0.0, 0));
+        entry.0 += r.rating;
+        entry.1 += 1;
+    }
+    sums.into_iter().map(|(mid, (sum, count))| (mid, sum / count as f32)).collect()
+}
+
+// Adjusted cosine similarity between two movies over users who rated both.
+// Inputs:
+// - ratings_i, ratings_j: &HashMap<u32, f32> --> user_id --> rating, one per movie
+// - mean_i, mean_j: f32 --> the two movies' own means (for centering)
+// Output: f32 --> similarity in [-1, 1], 0.0 if there are no common raters
+// Logic:
+// - Find users who rated both movies
+// - Center each movie's ratings by its own mean, then take cosine similarity
+fn adjusted_cosine(
+    ratings_i: &HashMap<u32, f32>,
+    ratings_j: &HashMap<u32, f32>,
+    mean_i: f32,
+    mean_j: f32,
+) -> f32 {
+    let mut dot = 0.0;
+    let mut norm_i = 0.0;
+    let mut norm_j = 0.0;
+    for (uid, &ri) in ratings_i {
+        if let Some(&rj) = ratings_j.get(uid) {
+            let di = ri - mean_i;
+            let dj = rj - mean_j;
+            dot += di * dj;
+            norm_i += di * di;
+            norm_j += dj * dj;
+        }
+    }
+    if norm_i == 0.0 || norm_j == 0.0 { 0.0 } else { dot / (norm_i.sqrt() * norm_j.sqrt()) }
+}
+
+// Precompute the item-item similarity map, since item similarities change slowly
+// relative to user preferences and can be reused across many recommend calls.
+// Output: HashMap<(u32, u32), f32> --> (movie_id, movie_id) --> adjusted cosine similarity,
+// keyed with the smaller ID first so each pair is stored once
+pub fn build_item_similarities(ratings: &[Rating]) -> HashMap<(u32, u32), f32> {
+    let means = item_means(ratings);
+
+    // Group ratings by movie: movie_id --> (user_id --> rating)
+    let mut by_movie: HashMap<u32, HashMap<u32, f32>> = HashMap::new();
+    for r in ratings {
+        by_movie.entry(r.movie_id).or_default().insert(r.user_id, r.rating);
+    }
+
+    let mut mids: Vec<u32> = by_movie.keys().copied().collect();
+    mids.sort_unstable();
+
+    let mut sims = HashMap::new();
+    for (idx, &i) in mids.iter().enumerate() {
+        for &j in &mids[idx + 1..] {
+            let sim = adjusted_cosine(&by_movie[&i], &by_movie[&j], means[&i], means[&j]);
+            if sim != 0.0 {
+                sims.insert((i, j), sim);
+            }
+        }
+    }
+    sims
+}
+
+// Look up the similarity between two movies regardless of argument order, since
+// build_item_similarities only stores each pair once with the smaller ID first.
+fn similarity(sims: &HashMap<(u32, u32), f32>, i: u32, j: u32) -> f32 {
+    if i == j {
+        return 1.0;
+    }
+    let key = if i < j { (i, j) } else { (j, i) };
+    sims.get(&key).copied().unwrap_or(0.0)
+}
+
+// Struct ItemCfModel: holds the precomputed item-item similarity map so it can be
+// reused across many predictions instead of recomputing it per query, since item
+// similarities change slowly relative to user preferences.
+pub struct ItemCfModel {
+    sims: HashMap<(u32, u32), f32>,
+}
+
+impl ItemCfModel {
+    pub fn train(ratings: &[Rating]) -> Self {
+        ItemCfModel { sims: build_item_similarities(ratings) }
+    }
+
+    // Predict user u's rating of movie i as r̄_u + Σ sim(i,j)·(r_{u,j} − r̄_u) / Σ |sim(i,j)|
+    // over the user's rated movies j (from `ratings`, which may be a train split distinct
+    // from what this model was trained on). Returns 0.0 if u rated nothing at all, and
+    // falls back to r̄_u when none of u's rated movies has a nonzero similarity to i
+    // (the zero-denominator case).
+    pub fn predict(&self, user_id: u32, movie_id: u32, ratings: &[Rating]) -> f32 {
+        let user_ratings: HashMap<u32, f32> = ratings
+            .iter()
+            .filter(|r| r.user_id == user_id)
+            .map(|r| (r.movie_id, r.rating))
+            .collect();
+        if user_ratings.is_empty() {
+            return 0.0;
+        }
+        let user_mean = user_ratings.values().sum::<f32>() / user_ratings.len() as f32;
+
+        let mut weighted_sum = 0.0;
+        let mut weight_total = 0.0;
+        for (&other_mid, &r_uj) in &user_ratings {
+            let sim = similarity(&self.sims, movie_id, other_mid);
+            weighted_sum += sim * (r_uj - user_mean);
+            weight_total += sim.abs();
+        }
+        if weight_total > 0.0 { user_mean + weighted_sum / weight_total } else { user_mean }
+    }
+}
+
+// Recommend unrated movies for a user via item-based collaborative filtering.
+// Inputs:
+// - user_id: target user
+// - ratings: &[Rating] --> all ratings
+// - top_n: usize --> number of recommendations
+// Output: Vec<(u32, f32)> --> (movie_id, predicted rating) pairs, highest first
+// Logic:
+// 1. Precompute item-item similarities
+// 2. For each unrated movie i, predict r̄_u + Σ sim(i,j)·(r_{u,j} − r̄_u) / Σ |sim(i,j)|
+//    over the user's rated movies j
+// 3. Guard against a zero denominator (no similar rated movies)
+// 4. Sort by predicted rating and return the top-N
+pub fn recommend_item_based(user_id: u32, ratings: &[Rating], top_n: usize) -> Vec<(u32, f32)> {
+    let model = ItemCfModel::train(ratings);
+
+    let rated: std::collections::HashSet<u32> = ratings
+        .iter()
+        .filter(|r| r.user_id == user_id)
+        .map(|r| r.movie_id)
+        .collect();
+    if rated.is_empty() {
+        return Vec::new();
+    }
+
+    let mut mids: Vec<u32> = ratings.iter().map(|r| r.movie_id).collect();
+    mids.sort_unstable();
+    mids.dedup();
+
+    let mut preds: Vec<(u32, f32)> = mids
+        .into_iter()
+        .filter(|mid| !rated.contains(mid))
+        .map(|mid| (mid, model.predict(user_id, mid, ratings)))
+        .collect();
+
+    preds.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap().then(a.0.cmp(&b.0)));
+    preds.truncate(top_n);
+    preds
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rating(user_id: u32, movie_id: u32, rating: f32) -> Rating {
+        Rating { user_id, movie_id, rating, timestamp: 0 }
+    }
+
+    // Test: movies 10 and 20 are always rated together at similar relative levels, so
+    // a user who rated 10 highly (relative to their own mean) should see 20 predicted high.
+    #[test]
+    fn test_recommend_item_based_prefers_correlated_movie() {
+        let ratings = vec![
+            rating(1, 10, 5.0),
+            rating(1, 20, 5.0),
+            rating(1, 30, 1.0),
+            rating(2, 10, 4.0),
+            rating(2, 20, 4.0),
+            rating(2, 30, 2.0),
+            rating(3, 10, 5.0),
+            rating(3, 30, 1.0),
+        ];
+        let recs = recommend_item_based(3, &ratings, 1);
+        assert_eq!(recs.len(), 1);
+        assert_eq!(recs[0].0, 20);
+    }
+
+    // Test: a movie pair with no common raters gets similarity 0.0 and contributes
+    // nothing to the prediction instead of panicking on a zero-length dot product.
+    #[test]
+    fn test_adjusted_cosine_no_common_raters_is_zero() {
+        let ratings_i: HashMap<u32, f32> = [(1, 5.0)].into_iter().collect();
+        let ratings_j: HashMap<u32, f32> = [(2, 4.0)].into_iter().collect();
+        assert_eq!(adjusted_cosine(&ratings_i, &ratings_j, 5.0, 4.0), 0.0);
+    }
+}
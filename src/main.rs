@@ -5,17 +5,31 @@
 
 mod movie_names; // Module: loads movie titles from CSV for lookup
 mod top_movies;  // Module: fetches a user’s top-rated movies
+mod svd_reco;    // Module: model-based recommendations via SVD matrix factorization
+mod item_cf;     // Module: item-based collaborative filtering via adjusted cosine similarity
+mod eval;        // Module: held-out RMSE/MAE evaluation harness for comparing recommenders
+mod graph_reco;  // Module: random-walk-with-restart recommendations over a bipartite graph
+mod content_based; // Module: genre-based content recommendations, plus a collaborative hybrid
 
 use std::collections::HashMap;
 use std::error::Error;
 
 use movie_names::MovieDb;
 use top_movies::top_movies;
-use nalgebra::DVector;
+use svd_reco::recommend_svd;
+use item_cf::recommend_item_based;
+use graph_reco::recommend_graph_based;
+use content_based::{recommend_content_based, recommend_hybrid};
 
-// Type alias: maps user IDs to their dense rating vectors
-// Each vector holds the user’s ratings, with positions in alignment with movie indices
-type RatingMap = HashMap<u32, DVector<f32>>;
+// A user's ratings stored sparsely as (movie_index, rating) pairs, sorted by
+// movie_index, instead of one slot per movie in the whole catalog. This keeps
+// memory proportional to the number of ratings rather than users×movies.
+type SparseVector = Vec<(usize, f32)>;
+
+// Type alias: maps user IDs to their sparse rating vectors
+// Each vector holds only the user’s nonzero ratings, with indices in alignment
+// with the catalog-wide movie position map built in `build_user_vectors`
+type RatingMap = HashMap<u32, SparseVector>;
 
 // Struct Rating: represents a single user rating
 // Fields:
@@ -23,7 +37,7 @@ type RatingMap = HashMap<u32, DVector<f32>>;
 // - movie_id: the movie being rated
 // - rating: numeric score (e.g., 1.0–5.0)
 // - timestamp: when the rating was submitted (UNIX time)
-#[derive(Debug, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct Rating {
     user_id: u32,
@@ -61,6 +75,58 @@ fn main() -> Result<(), Box<dyn Error>> {
         println!("- {}: {} (Rating: {:.1})", mid, title, rating);
     }
 
+    // Step 4: Recommend movies via SVD matrix factorization
+    let svd_recs = recommend_svd(user_id, &ratings, 20, top_n);
+    println!("\nSVD-based recommendations for user {}:", user_id);
+    for (mid, pred) in svd_recs {
+        let title = movie_db.get_title(mid).unwrap_or("<unknown>");
+        println!("- {}: {} (Predicted: {:.2})", mid, title, pred);
+    }
+
+    // Step 5: Recommend movies via item-based collaborative filtering
+    let item_recs = recommend_item_based(user_id, &ratings, top_n);
+    println!("\nItem-based recommendations for user {}:", user_id);
+    for (mid, pred) in item_recs {
+        let title = movie_db.get_title(mid).unwrap_or("<unknown>");
+        println!("- {}: {} (Predicted: {:.2})", mid, title, pred);
+    }
+
+    // Step 6: Evaluate all predictors against a held-out test set
+    println!("\nHeld-out evaluation (RMSE/MAE):");
+    eval::report(&ratings);
+
+    // Step 7: Demonstrate fuzzy title search, e.g. for a human-entered (possibly
+    // misspelled) title with no known movie ID
+    let query = "stpr wars";
+    println!("\nDid you mean (for \"{}\"):", query);
+    for (mid, title, score) in movie_db.search_title(query, 3) {
+        println!("- {}: {} (score: {:.2})", mid, title, score);
+    }
+
+    // Step 8: Recommend movies via random walk with restart over the user-movie graph
+    let graph_recs = recommend_graph_based(user_id, &ratings, top_n);
+    println!("\nGraph-based recommendations for user {}:", user_id);
+    for (mid, prob) in graph_recs {
+        let title = movie_db.get_title(mid).unwrap_or("<unknown>");
+        println!("- {}: {} (probability: {:.4})", mid, title, prob);
+    }
+
+    // Step 9: Recommend movies via genre-based content similarity
+    let content_recs = recommend_content_based(user_id, &ratings, &movie_db, top_n);
+    println!("\nContent-based recommendations for user {}:", user_id);
+    for (mid, score) in content_recs {
+        let title = movie_db.get_title(mid).unwrap_or("<unknown>");
+        println!("- {}: {} (genre similarity: {:.2})", mid, title, score);
+    }
+
+    // Step 10: Recommend movies via a content/collaborative hybrid blend
+    let hybrid_recs = recommend_hybrid(user_id, &ratings, &movie_db, top_n, 0.5);
+    println!("\nHybrid recommendations for user {}:", user_id);
+    for (mid, score) in hybrid_recs {
+        let title = movie_db.get_title(mid).unwrap_or("<unknown>");
+        println!("- {}: {} (blended score: {:.2})", mid, title, score);
+    }
+
     Ok(())
 }
 
@@ -76,42 +142,59 @@ fn load_ratings(path: &str) -> Result<Vec<Rating>, Box<dyn Error>> {
     Ok(ratings)
 }
 
-// Build rating vectors per user
+// Build sparse rating vectors per user
 // Inputs:
 // - ratings: &[Rating] --> all ratings
 // Output:
-// - RatingMap --> maps user IDs to the rating vectors (length = number of unique movies)
+// - RatingMap --> maps user IDs to sparse (movie_index, rating) vectors, sorted by index
 // Logic:
 // - Build a unique, sorted movie list (mids)
 // - Create a position map (movie ID --> vector index)
-// - Fill each user’s vector with their ratings at the correct positions
+// - Push each user’s (index, rating) pairs, then sort each by index so
+//   cosine_similarity can walk two users’ vectors with a merge-style scan
 fn build_user_vectors(ratings: &[Rating]) -> RatingMap {
     let mut mids: Vec<u32> = ratings.iter().map(|r| r.movie_id).collect();
     mids.sort_unstable();
     mids.dedup();
-    let m = mids.len();
     let pos: HashMap<u32, usize> = mids.iter().copied().enumerate().map(|(i, id)| (id, i)).collect();
 
-    let mut map = HashMap::new();
+    let mut map: RatingMap = HashMap::new();
     for r in ratings {
-        let vec = map.entry(r.user_id).or_insert_with(|| DVector::from_element(m, 0.0));
-        vec[pos[&r.movie_id]] = r.rating;
+        map.entry(r.user_id).or_default().push((pos[&r.movie_id], r.rating));
+    }
+    for vec in map.values_mut() {
+        vec.sort_unstable_by_key(|&(idx, _)| idx);
     }
     map
 }
 
-// Calculate cosine similarity between two rating vectors.
+// Calculate cosine similarity between two sparse rating vectors.
 // Inputs:
-// - a, b: &DVector<f32> --> two user rating vectors
+// - a, b: &SparseVector --> two users' (movie_index, rating) pairs, each sorted by index
 // Output:
 // - f32 --> similarity score (0.0 if either vector is zero)
 // Logic:
-// - Compute dot product
-// - Normalize by vector magnitudes
-fn cosine_similarity(a: &DVector<f32>, b: &DVector<f32>) -> f32 {
-    let dot = a.dot(b);
-    let na = a.norm();
-    let nb = b.norm();
+// - Walk both sorted vectors in lockstep, accumulating the dot product only where
+//   indices match (the other entries contribute 0 either way)
+// - Track each vector's norm from its own nonzero entries
+// - Normalize the dot product by the two norms
+fn cosine_similarity(a: &SparseVector, b: &SparseVector) -> f32 {
+    let mut i = 0;
+    let mut j = 0;
+    let mut dot = 0.0;
+    while i < a.len() && j < b.len() {
+        match a[i].0.cmp(&b[j].0) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                dot += a[i].1 * b[j].1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    let na: f32 = a.iter().map(|&(_, v)| v * v).sum::<f32>().sqrt();
+    let nb: f32 = b.iter().map(|&(_, v)| v * v).sum::<f32>().sqrt();
     if na == 0.0 || nb == 0.0 { 0.0 } else { dot / (na * nb) }
 }
 
@@ -169,7 +252,8 @@ fn recommend_movies(
     let mut weights: HashMap<u32, f32> = HashMap::new();
     for &(uid, sim) in sims.iter().take(k) {
         for r in ratings.iter().filter(|r| r.user_id == uid) {
-            if target[pos[&r.movie_id]] == 0.0 {
+            let already_rated = target.binary_search_by_key(&pos[&r.movie_id], |&(idx, _)| idx).is_ok();
+            if !already_rated {
                 *scores.entry(r.movie_id).or_default() += sim * r.rating;
                 *weights.entry(r.movie_id).or_default() += sim;
             }